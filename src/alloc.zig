@@ -11,4 +11,5 @@ pub const options = @import("alloc/options.zig");
 pub const Global = @import("alloc/Global.zig");
 pub const Layout = @import("alloc/Layout.zig");
 pub const AllocError = errors.AllocError;
+pub const LayoutError = errors.LayoutError;
 pub const AllocOptions = options.AllocOptions;
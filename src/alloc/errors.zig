@@ -1,2 +1,5 @@
 /// Represents an error possibly encountered whilst trying to allocate a block of memory with our global allocator.
 pub const AllocError = struct{};
+
+/// Represents an error encountered whilst computing a `Layout`, e.g. when its size would overflow `usize`.
+pub const LayoutError = error{Overflow};
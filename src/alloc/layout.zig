@@ -13,6 +13,10 @@
 
 const Self = @This();
 
+const LayoutError = @import("errors.zig").LayoutError;
+
+const math = @import("std").math;
+
 size: usize,
 alignment: usize,
 
@@ -31,6 +35,27 @@ pub fn from(comptime s: usize, comptime a: usize) *Layout {
    };
 }
 
+/// Creates the `Layout` of `[n]T` at compile time: `@sizeOf(T) * n` bytes,
+/// aligned to `@alignOf(T)`.
+///
+/// `@sizeOf(T) * n` must not overflow `usize`; if it does, compilation fails.
+///
+///
+/// # Examples
+///
+/// ```zig
+/// const x: Layout = comptime Layout.array_const(u32, 4); // 16 bytes, 4-aligned
+/// const y: Layout = comptime Layout.array_const(u8, 0);  // 0 bytes, 1-aligned
+/// ```
+pub fn array_const(comptime T: type, comptime n: usize) Self {
+   const s = comptime (math.mul(usize, @sizeOf(T), n) catch @compileError("array layout size overflows usize"));
+
+   return Self{
+      .size = s,
+      .alignment = @alignOf(T),
+   };
+}
+
 /// Gets the `size` field from Self.
 /// 
 /// 
@@ -66,3 +91,42 @@ pub fn size(self: *Self) usize {
 pub fn alignment(self: *Self) usize {
    return self.alignment;
 }
+
+/// Creates a `Layout` for `n` instances of Self placed back-to-back, with no
+/// padding between them.
+///
+/// The resulting layout keeps the alignment of a single element; only its start
+/// is aligned, not each element. Returns `LayoutError.Overflow` if the total size
+/// does not fit in a `usize`.
+///
+///
+/// # Examples
+///
+/// ```zig
+/// var x: Layout = Layout.from(3, 4);
+///
+/// // 6 bytes, 4-aligned: the second element starts at offset 3.
+/// var y: Layout = try x.repeat_packed(2);
+/// ```
+pub fn repeat_packed(self: *Self, n: usize) LayoutError!Self {
+   return Self{
+      .size = try math.mul(usize, self.size, n),
+      .alignment = self.alignment,
+   };
+}
+
+/// Returns whether a value of type `T` fits in the block described by Self,
+/// i.e. Self is at least as large and at least as strictly aligned as `T`.
+///
+///
+/// # Examples
+///
+/// ```zig
+/// var x: Layout = Layout.from(8, 8);
+///
+/// var y: bool = x.fits(u64); // true
+/// var z: bool = x.fits(u128); // false
+/// ```
+pub fn fits(self: *Self, comptime T: type) bool {
+   return self.size >= @sizeOf(T) and self.alignment >= @alignOf(T);
+}